@@ -0,0 +1,55 @@
+use super::point::{Point2, Point3};
+use super::vec::Vector;
+
+pub const DEFAULT_EPSILON: f64 = 1e-6;
+pub const DEFAULT_MAX_RELATIVE: f64 = 1e-6;
+
+pub trait ApproxEq {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool;
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool;
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.abs_diff_eq(other, DEFAULT_EPSILON) || self.relative_eq(other, DEFAULT_MAX_RELATIVE)
+    }
+}
+
+fn component_abs_diff_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    (a - b).abs() <= epsilon
+}
+
+fn component_relative_eq(a: f64, b: f64, max_relative: f64) -> bool {
+    let abs_diff = (a - b).abs();
+    let largest = a.abs().max(b.abs());
+    abs_diff <= largest * max_relative
+}
+
+impl<const N: usize> ApproxEq for Vector<N, f64> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (0..N).all(|i| component_abs_diff_eq(self[i], other[i], epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        (0..N).all(|i| component_relative_eq(self[i], other[i], max_relative))
+    }
+}
+
+impl ApproxEq for Point2<f64> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (0..2).all(|i| component_abs_diff_eq(self[i], other[i], epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        (0..2).all(|i| component_relative_eq(self[i], other[i], max_relative))
+    }
+}
+
+impl ApproxEq for Point3<f64> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (0..3).all(|i| component_abs_diff_eq(self[i], other[i], epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        (0..3).all(|i| component_relative_eq(self[i], other[i], max_relative))
+    }
+}
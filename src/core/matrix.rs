@@ -0,0 +1,188 @@
+use std::ops::{Index, IndexMut, Mul};
+
+use super::point::Point3;
+use super::vec::Vector3;
+
+const INVERSE_EPSILON: f64 = 1e-10;
+
+#[derive(Clone, Copy)]
+pub struct Matrix<const R: usize, const C: usize, T>([[T; C]; R]);
+
+pub type Matrix4x4 = Matrix<4, 4, f64>;
+
+impl<const R: usize, const C: usize, T> Matrix<R, C, T> {
+    pub fn new(values: [[T; C]; R]) -> Self {
+        Self(values)
+    }
+
+    pub fn take_inner(self) -> [[T; C]; R] {
+        self.0
+    }
+}
+
+impl<const R: usize, const C: usize, T> Index<(usize, usize)> for Matrix<R, C, T> {
+    type Output = T;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < R && col < C);
+        &self.0[row][col]
+    }
+}
+
+impl<const R: usize, const C: usize, T> IndexMut<(usize, usize)> for Matrix<R, C, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < R && col < C);
+        &mut self.0[row][col]
+    }
+}
+
+impl<const R: usize, const C: usize, T> PartialEq for Matrix<R, C, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        for i in 0..R {
+            for j in 0..C {
+                if self[(i, j)] != other[(i, j)] {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+macro_rules! num_matrix_ops_impl {
+    ($type:ty) => {
+        impl<const R: usize, const C: usize> Matrix<R, C, $type> {
+            pub fn zero() -> Self {
+                Self([[0 as $type; C]; R])
+            }
+
+            pub fn transpose(&self) -> Matrix<C, R, $type> {
+                let mut result = Matrix::<C, R, $type>::zero();
+                for i in 0..R {
+                    for j in 0..C {
+                        result[(j, i)] = self[(i, j)];
+                    }
+                }
+                result
+            }
+        }
+
+        impl<const N: usize> Matrix<N, N, $type> {
+            pub fn identity() -> Self {
+                let mut result = Self::zero();
+                for i in 0..N {
+                    result[(i, i)] = 1 as $type;
+                }
+                result
+            }
+
+            pub fn pow(&self, mut n: u64) -> Self {
+                let mut result = Self::identity();
+                let mut base = *self;
+                while n > 0 {
+                    if n & 1 == 1 {
+                        result = result * base;
+                    }
+                    base = base * base;
+                    n >>= 1;
+                }
+                result
+            }
+        }
+
+        impl<const R: usize, const C: usize, const C2: usize> Mul<Matrix<C, C2, $type>>
+            for Matrix<R, C, $type>
+        {
+            type Output = Matrix<R, C2, $type>;
+            fn mul(self, rhs: Matrix<C, C2, $type>) -> Self::Output {
+                let mut result = Matrix::<R, C2, $type>::zero();
+                for i in 0..R {
+                    for j in 0..C2 {
+                        let mut acc = 0 as $type;
+                        for k in 0..C {
+                            acc += self[(i, k)] * rhs[(k, j)];
+                        }
+                        result[(i, j)] = acc;
+                    }
+                }
+                result
+            }
+        }
+    };
+}
+
+num_matrix_ops_impl!(i32);
+num_matrix_ops_impl!(f64);
+
+impl<const N: usize> Matrix<N, N, f64> {
+    pub fn inverse(&self) -> Option<Self> {
+        let mut a = *self;
+        let mut inv = Self::identity();
+
+        for col in 0..N {
+            let mut pivot = col;
+            for row in (col + 1)..N {
+                if a[(row, col)].abs() > a[(pivot, col)].abs() {
+                    pivot = row;
+                }
+            }
+
+            if a[(pivot, col)].abs() < INVERSE_EPSILON {
+                return None;
+            }
+
+            a.0.swap(pivot, col);
+            inv.0.swap(pivot, col);
+
+            let scale = a[(col, col)];
+            for j in 0..N {
+                a[(col, j)] /= scale;
+                inv[(col, j)] /= scale;
+            }
+
+            for row in 0..N {
+                if row == col {
+                    continue;
+                }
+                let factor = a[(row, col)];
+                for j in 0..N {
+                    a[(row, j)] -= factor * a[(col, j)];
+                    inv[(row, j)] -= factor * inv[(col, j)];
+                }
+            }
+        }
+
+        Some(inv)
+    }
+}
+
+impl Mul<Point3<f64>> for Matrix4x4 {
+    type Output = Point3<f64>;
+    fn mul(self, rhs: Point3<f64>) -> Self::Output {
+        let homogeneous = [rhs[0], rhs[1], rhs[2], 1.];
+        let mut result = [0.; 4];
+        for i in 0..4 {
+            for k in 0..4 {
+                result[i] += self[(i, k)] * homogeneous[k];
+            }
+        }
+        let w = result[3];
+        Point3::new([result[0] / w, result[1] / w, result[2] / w])
+    }
+}
+
+impl Mul<Vector3<f64>> for Matrix4x4 {
+    type Output = Vector3<f64>;
+    fn mul(self, rhs: Vector3<f64>) -> Self::Output {
+        let homogeneous = [rhs[0], rhs[1], rhs[2], 0.];
+        let mut result = [0.; 4];
+        for i in 0..4 {
+            for k in 0..4 {
+                result[i] += self[(i, k)] * homogeneous[k];
+            }
+        }
+        Vector3::new([result[0], result[1], result[2]])
+    }
+}
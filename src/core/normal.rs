@@ -0,0 +1,183 @@
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+use super::vec::{MulAdd, Vector3, Vector3f};
+
+#[derive(Clone, Copy)]
+pub struct Normal3<T>([T; 3]);
+
+pub type Normal3f = Normal3<f64>;
+
+impl<T> Normal3<T> {
+    pub fn new(values: [T; 3]) -> Self {
+        Self(values)
+    }
+
+    pub fn take_inner(self) -> [T; 3] {
+        self.0
+    }
+}
+
+impl<T> Index<usize> for Normal3<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < 3);
+        &self.0[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Normal3<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < 3);
+        &mut self.0[index]
+    }
+}
+
+impl<T> Normal3<T> {
+    pub fn x(&self) -> &T {
+        &self[0]
+    }
+
+    pub fn y(&self) -> &T {
+        &self[1]
+    }
+
+    pub fn z(&self) -> &T {
+        &self[2]
+    }
+}
+
+impl<T> From<Vector3<T>> for Normal3<T> {
+    fn from(vec: Vector3<T>) -> Self {
+        Self(vec.take_inner())
+    }
+}
+
+impl<T> From<Normal3<T>> for Vector3<T> {
+    fn from(normal: Normal3<T>) -> Self {
+        Vector3::new(normal.0)
+    }
+}
+
+macro_rules! num_normal3_ops_impl {
+    ($type:ty) => {
+        impl Normal3<$type> {
+            pub fn zero() -> Self {
+                Self([0 as $type; 3])
+            }
+
+            pub fn dot(&self, other: &Vector3<$type>) -> $type {
+                let mut result = 0 as $type;
+                for i in 0..3 {
+                    result = self[i].mul_add(other[i], result);
+                }
+                result
+            }
+
+            pub fn abs_dot(&self, other: &Vector3<$type>) -> $type {
+                self.dot(other).abs()
+            }
+
+            pub fn length(&self) -> f64 {
+                self.length_squared().sqrt()
+            }
+
+            pub fn normalize(&self) -> Normal3<f64> {
+                let length = self.length();
+                Normal3([
+                    self[0] as f64 / length,
+                    self[1] as f64 / length,
+                    self[2] as f64 / length,
+                ])
+            }
+        }
+
+        impl Add for Normal3<$type> {
+            type Output = Self;
+            fn add(mut self, rhs: Self) -> Self::Output {
+                self[0] += rhs[0];
+                self[1] += rhs[1];
+                self[2] += rhs[2];
+                self
+            }
+        }
+
+        impl Sub for Normal3<$type> {
+            type Output = Self;
+            fn sub(mut self, rhs: Self) -> Self::Output {
+                self[0] -= rhs[0];
+                self[1] -= rhs[1];
+                self[2] -= rhs[2];
+                self
+            }
+        }
+
+        impl Neg for Normal3<$type> {
+            type Output = Self;
+            fn neg(mut self) -> Self::Output {
+                self[0] = -self[0];
+                self[1] = -self[1];
+                self[2] = -self[2];
+                self
+            }
+        }
+
+        impl Mul<$type> for Normal3<$type> {
+            type Output = Self;
+            fn mul(mut self, rhs: $type) -> Self::Output {
+                self[0] *= rhs;
+                self[1] *= rhs;
+                self[2] *= rhs;
+                self
+            }
+        }
+
+        impl Div<$type> for Normal3<$type> {
+            type Output = Self;
+            fn div(mut self, rhs: $type) -> Self::Output {
+                self[0] /= rhs;
+                self[1] /= rhs;
+                self[2] /= rhs;
+                self
+            }
+        }
+
+        impl PartialEq for Normal3<$type> {
+            fn eq(&self, other: &Self) -> bool {
+                self[0] == other[0] && self[1] == other[1] && self[2] == other[2]
+            }
+        }
+    };
+}
+
+num_normal3_ops_impl!(i32);
+num_normal3_ops_impl!(f64);
+
+impl Normal3<i32> {
+    pub fn length_squared(&self) -> f64 {
+        let mut squared_sum = 0.;
+        for i in 0..3 {
+            squared_sum += (self[i] * self[i]) as f64;
+        }
+        squared_sum
+    }
+}
+
+impl Normal3<f64> {
+    pub fn length_squared(&self) -> f64 {
+        let mut squared_sum = 0.;
+        for i in 0..3 {
+            squared_sum = self[i].mul_add(self[i], squared_sum);
+        }
+        squared_sum
+    }
+}
+
+impl Normal3f {
+    pub fn face_forward(&self, v: &Vector3f) -> Self {
+        if self.dot(v) < 0. {
+            -*self
+        } else {
+            *self
+        }
+    }
+}
@@ -1,7 +1,25 @@
-use std::ops::{Add, AddAssign, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, SubAssign};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 pub struct Vector<const N: usize, T>([T; N]);
 
+pub trait MulAdd {
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
+
+impl MulAdd for f64 {
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        f64::mul_add(self, a, b)
+    }
+}
+
+impl MulAdd for i32 {
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+}
+
 impl<T, const N: usize> Default for Vector<N, T>
 where
     T: Default + Copy,
@@ -113,6 +131,87 @@ where
     }
 }
 
+macro_rules! impl_binary_operator {
+    ($Op:ident :: $op:ident, |$lhs:ident, $rhs:ident| $body:expr) => {
+        impl<const N: usize, T> $Op for Vector<N, T>
+        where
+            T: $Op<Output = T> + Copy,
+        {
+            type Output = Vector<N, T>;
+            fn $op(self, rhs: Vector<N, T>) -> Vector<N, T> {
+                let ($lhs, $rhs) = (&self, &rhs);
+                $body
+            }
+        }
+
+        impl<'a, const N: usize, T> $Op<&'a Vector<N, T>> for Vector<N, T>
+        where
+            T: $Op<Output = T> + Copy,
+        {
+            type Output = Vector<N, T>;
+            fn $op(self, rhs: &'a Vector<N, T>) -> Vector<N, T> {
+                let ($lhs, $rhs) = (&self, rhs);
+                $body
+            }
+        }
+
+        impl<'a, const N: usize, T> $Op<Vector<N, T>> for &'a Vector<N, T>
+        where
+            T: $Op<Output = T> + Copy,
+        {
+            type Output = Vector<N, T>;
+            fn $op(self, rhs: Vector<N, T>) -> Vector<N, T> {
+                let ($lhs, $rhs) = (self, &rhs);
+                $body
+            }
+        }
+
+        impl<'a, 'b, const N: usize, T> $Op<&'b Vector<N, T>> for &'a Vector<N, T>
+        where
+            T: $Op<Output = T> + Copy,
+        {
+            type Output = Vector<N, T>;
+            fn $op(self, rhs: &'b Vector<N, T>) -> Vector<N, T> {
+                let ($lhs, $rhs) = (self, rhs);
+                $body
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_operator {
+    ($Op:ident :: $op:ident, |$lhs:ident, $rhs:ident| $body:expr) => {
+        impl<const N: usize, T, U> $Op<U> for Vector<N, T>
+        where
+            T: $Op<U, Output = T> + Copy,
+            U: Copy,
+        {
+            type Output = Vector<N, T>;
+            fn $op(self, rhs: U) -> Vector<N, T> {
+                let ($lhs, $rhs) = (&self, rhs);
+                $body
+            }
+        }
+
+        impl<'a, const N: usize, T, U> $Op<U> for &'a Vector<N, T>
+        where
+            T: $Op<U, Output = T> + Copy,
+            U: Copy,
+        {
+            type Output = Vector<N, T>;
+            fn $op(self, rhs: U) -> Vector<N, T> {
+                let ($lhs, $rhs) = (self, rhs);
+                $body
+            }
+        }
+    };
+}
+
+impl_binary_operator!(Add::add, |lhs, rhs| Vector(std::array::from_fn(|i| lhs[i] + rhs[i])));
+impl_binary_operator!(Sub::sub, |lhs, rhs| Vector(std::array::from_fn(|i| lhs[i] - rhs[i])));
+impl_scalar_operator!(Mul::mul, |lhs, rhs| Vector(std::array::from_fn(|i| lhs[i] * rhs)));
+impl_scalar_operator!(Div::div, |lhs, rhs| Vector(std::array::from_fn(|i| lhs[i] / rhs)));
+
 pub type Vector2<T> = Vector<2, T>;
 pub type Vector3<T> = Vector<3, T>;
 
@@ -165,7 +264,7 @@ macro_rules! num_vec_ops_impl {
             pub fn dot(&self, other: &Self) -> $type {
                 let mut result = 0 as $type;
                 for i in 0..N {
-                    result += self[i] * other[i];
+                    result = self[i].mul_add(other[i], result);
                 }
                 result
             }
@@ -174,14 +273,6 @@ macro_rules! num_vec_ops_impl {
                 self.dot(other).abs()
             }
 
-            pub fn length_squared(&self) -> f64 {
-                let mut squared_sum = 0.;
-                for i in 0..N {
-                    squared_sum += (self[i] * self[i]) as f64;
-                }
-                squared_sum
-            }
-
             pub fn length(&self) -> f64 {
                 self.length_squared().sqrt()
             }
@@ -223,9 +314,9 @@ macro_rules! num_vec_ops_impl {
 
         impl Vector3<$type> {
             pub fn cross(&self, other: &Self) -> Self {
-                let x = self.y() * other.z() - self.z() * other.y();
-                let y = self.z() * other.x() - self.x() * other.z();
-                let z = self.x() * other.y() - self.y() * other.x();
+                let x = (*self.y()).mul_add(*other.z(), -(self.z() * other.y()));
+                let y = (*self.z()).mul_add(*other.x(), -(self.x() * other.z()));
+                let z = (*self.x()).mul_add(*other.y(), -(self.y() * other.x()));
                 Self([x, y, z])
             }
         }
@@ -235,6 +326,26 @@ macro_rules! num_vec_ops_impl {
 num_vec_ops_impl!(i32);
 num_vec_ops_impl!(f64);
 
+impl<const N: usize> Vector<N, i32> {
+    pub fn length_squared(&self) -> f64 {
+        let mut squared_sum = 0.;
+        for i in 0..N {
+            squared_sum += (self[i] * self[i]) as f64;
+        }
+        squared_sum
+    }
+}
+
+impl<const N: usize> Vector<N, f64> {
+    pub fn length_squared(&self) -> f64 {
+        let mut squared_sum = 0.;
+        for i in 0..N {
+            squared_sum = self[i].mul_add(self[i], squared_sum);
+        }
+        squared_sum
+    }
+}
+
 pub fn coordinate_system(v1: &Vector3<f64>, v2: &mut Vector3<f64>, v3: &mut Vector3<f64>) {
     if v1.x().abs() > v1.y().abs() {
         v2.set([-v1.z(), 0., *v1.x()]);
@@ -0,0 +1,197 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::point::Point3f;
+use super::vec::Vector3f;
+
+pub const LANES: usize = 4;
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub struct f64x4([f64; LANES]);
+
+impl f64x4 {
+    pub fn new(values: [f64; LANES]) -> Self {
+        Self(values)
+    }
+
+    pub fn splat(value: f64) -> Self {
+        Self([value; LANES])
+    }
+
+    pub fn take_inner(self) -> [f64; LANES] {
+        self.0
+    }
+
+    pub fn sqrt(self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i].sqrt()))
+    }
+}
+
+macro_rules! impl_f64x4_operator {
+    ($Op:ident :: $op:ident) => {
+        impl $Op for f64x4 {
+            type Output = f64x4;
+            fn $op(self, rhs: f64x4) -> f64x4 {
+                f64x4(std::array::from_fn(|i| self.0[i].$op(rhs.0[i])))
+            }
+        }
+    };
+}
+
+impl_f64x4_operator!(Add::add);
+impl_f64x4_operator!(Sub::sub);
+impl_f64x4_operator!(Mul::mul);
+impl_f64x4_operator!(Div::div);
+
+#[derive(Clone, Copy)]
+pub struct Vector3fx4 {
+    pub x: f64x4,
+    pub y: f64x4,
+    pub z: f64x4,
+}
+
+#[derive(Clone, Copy)]
+pub struct Point3fx4 {
+    pub x: f64x4,
+    pub y: f64x4,
+    pub z: f64x4,
+}
+
+impl Vector3fx4 {
+    pub fn dot(&self, other: &Self) -> f64x4 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    pub fn length_squared(&self) -> f64x4 {
+        self.dot(self)
+    }
+
+    pub fn length(&self) -> f64x4 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        Self {
+            x: self.x / length,
+            y: self.y / length,
+            z: self.z / length,
+        }
+    }
+}
+
+impl Add for Vector3fx4 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub for Vector3fx4 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl Mul<f64x4> for Vector3fx4 {
+    type Output = Self;
+    fn mul(self, rhs: f64x4) -> Self {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl Div<f64x4> for Vector3fx4 {
+    type Output = Self;
+    fn div(self, rhs: f64x4) -> Self {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+        }
+    }
+}
+
+impl Point3fx4 {
+    pub fn distance_squared(&self, other: &Self) -> f64x4 {
+        (*self - *other).length_squared()
+    }
+
+    pub fn distance(&self, other: &Self) -> f64x4 {
+        self.distance_squared(other).sqrt()
+    }
+}
+
+impl Add<Vector3fx4> for Point3fx4 {
+    type Output = Self;
+    fn add(self, rhs: Vector3fx4) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub<Point3fx4> for Point3fx4 {
+    type Output = Vector3fx4;
+    fn sub(self, rhs: Point3fx4) -> Vector3fx4 {
+        Vector3fx4 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl From<[Vector3f; LANES]> for Vector3fx4 {
+    fn from(values: [Vector3f; LANES]) -> Self {
+        Self {
+            x: f64x4(std::array::from_fn(|lane| values[lane][0])),
+            y: f64x4(std::array::from_fn(|lane| values[lane][1])),
+            z: f64x4(std::array::from_fn(|lane| values[lane][2])),
+        }
+    }
+}
+
+impl From<Vector3fx4> for [Vector3f; LANES] {
+    fn from(wide: Vector3fx4) -> Self {
+        std::array::from_fn(|lane| Vector3f::new([wide.x.0[lane], wide.y.0[lane], wide.z.0[lane]]))
+    }
+}
+
+impl From<[Point3f; LANES]> for Point3fx4 {
+    fn from(values: [Point3f; LANES]) -> Self {
+        Self {
+            x: f64x4(std::array::from_fn(|lane| values[lane][0])),
+            y: f64x4(std::array::from_fn(|lane| values[lane][1])),
+            z: f64x4(std::array::from_fn(|lane| values[lane][2])),
+        }
+    }
+}
+
+impl From<Point3fx4> for [Point3f; LANES] {
+    fn from(wide: Point3fx4) -> Self {
+        std::array::from_fn(|lane| Point3f::new([wide.x.0[lane], wide.y.0[lane], wide.z.0[lane]]))
+    }
+}